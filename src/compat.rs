@@ -1,7 +1,15 @@
+//! Note on the `zeroize` feature used below and in `rand.rs`: turning it on
+//! requires the manifest to both declare the feature and forward it to
+//! `crypto-bigint`, e.g.
+//! `zeroize = ["dep:zeroize", "crypto-bigint/zeroize"]`, since `UInt<N>:
+//! Zeroize` only holds when crypto-bigint's own `zeroize` feature is
+//! enabled. This snapshot of the tree has no `Cargo.toml` to carry that
+//! wiring, so it's recorded here for whoever adds one.
+
 use core::ops::Rem;
 
-use crypto_bigint::subtle::ConditionallySelectable;
-use crypto_bigint::{Concat, RandomMod, Split, Zero};
+use crypto_bigint::subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+use crypto_bigint::{Concat, RandomMod, Split, Word, Zero};
 use crypto_bigint::{Integer, NonZero, UInt};
 use rand::{CryptoRng, RngCore};
 
@@ -72,10 +80,184 @@ where
             }
             this_power = mul_mod(&this_power, &this_power, m);
         }
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            this_power.zeroize();
+        }
         result
     }
 }
 
+/// Computes `-m^{-1} mod 2^w`, where `w` is the machine word width, via
+/// Newton's iteration. Seeded with `x = m` (correct mod 8 since `m` is odd),
+/// each step doubles the number of correct bits, so a handful of iterations
+/// suffice for any realistic word size.
+fn mont_inv_neg(m: Word) -> Word {
+    let mut x: Word = m;
+    for _ in 0..6 {
+        x = x.wrapping_mul((2 as Word).wrapping_sub(m.wrapping_mul(x)));
+    }
+    x.wrapping_neg()
+}
+
+fn word_to_uint<const N: usize>(w: Word) -> UInt<N> {
+    let mut words = [0 as Word; N];
+    words[0] = w;
+    UInt::from_words(words)
+}
+
+/// Montgomery reduction: given `t < m * R` (with `R = 2^(w*N)`), returns
+/// `t * R^-1 mod m`.
+///
+/// `t` is held in a `2N`-word container, but the running sum can briefly
+/// need one bit more than that holds (HAC 14.32/14.36): since `m < R`, the
+/// accumulated `t` is bounded by `2*m*R`, which can exceed `R^2` by up to a
+/// bit once `m` is more than about half of `R` — the case for this crate's
+/// own candidates whenever their bit size fills the whole `UInt<N>`. `carry`
+/// tracks that lost bit explicitly rather than letting `wrapping_add` drop
+/// it, and is folded back in after the final shift (there's no room for it
+/// in `t` itself, only in the already-shrunk result).
+fn mont_redc<const N: usize, const W: usize>(
+    mut t: <UInt<N> as Concat>::Output,
+    m: &UInt<N>,
+    m_inv: Word,
+) -> UInt<N>
+where
+    UInt<N>: crypto_bigint::Concat<Output = UInt<W>>,
+    UInt<W>: crypto_bigint::Split<Output = UInt<N>>,
+{
+    let w = Word::BITS as usize;
+    let mut carry: u8 = 0;
+    for i in 0..N {
+        let t_i = t.to_words()[i];
+        let u = t_i.wrapping_mul(m_inv);
+        let u_wide: UInt<N> = word_to_uint(u);
+        let (lo, hi) = u_wide.mul_wide(m);
+        let product: UInt<W> = hi.concat(&lo);
+        let new_t = t.wrapping_add(&(product << (w * i)));
+        // `product << (w * i)` is never negative, so `new_t < t` can only
+        // happen if the addition wrapped past the top of `t`'s container.
+        let overflowed: Choice = new_t.ct_lt(&t);
+        carry = u8::conditional_select(&carry, &1, overflowed);
+        t = new_t;
+    }
+    // The dropped carry bit represents `2^(2*w*N)`, which doesn't fit
+    // anywhere in `t`'s `2N`-word container. Shift `t` down first, *then*
+    // fold the carry in: dividing `carry * 2^(2*w*N)` by `R = 2^(w*N)`
+    // lands it on the lowest bit directly above the shifted-down value,
+    // which always has room since `t >> (w*N)` only occupies its bottom
+    // `N` words.
+    let reduced = (t >> (w * N)).wrapping_add(&(word_to_uint::<W>(carry as Word) << (w * N)));
+    let zero: UInt<N> = Zero::ZERO;
+    let m_wide: UInt<W> = zero.concat(m);
+    let is_less: Choice = reduced.ct_lt(&m_wide);
+    let subtracted = reduced.wrapping_sub(&m_wide);
+    let result = UInt::<W>::conditional_select(&subtracted, &reduced, is_less);
+    let (_hi, lo) = result.split();
+    lo
+}
+
+/// Montgomery multiplication: `a * b * R^-1 mod m`, where `a` and `b` are
+/// already in Montgomery form.
+fn mont_mul<const N: usize, const W: usize>(
+    a: &UInt<N>,
+    b: &UInt<N>,
+    m: &UInt<N>,
+    m_inv: Word,
+) -> UInt<N>
+where
+    UInt<N>: crypto_bigint::Concat<Output = UInt<W>>,
+    UInt<W>: crypto_bigint::Split<Output = UInt<N>>,
+{
+    let (lo, hi) = a.mul_wide(b);
+    let wide: UInt<W> = hi.concat(&lo);
+    mont_redc(wide, m, m_inv)
+}
+
+/// Returns the `i`-th bit of `x` as a [`Choice`], without branching on the
+/// bit's value.
+fn bit_choice<const N: usize>(x: &UInt<N>, i: usize) -> Choice {
+    let w = Word::BITS as usize;
+    let word = x.to_words()[i / w];
+    Choice::from(((word >> (i % w)) & 1) as u8)
+}
+
+/// Montgomery-form modular exponentiation. Constant-time with respect to
+/// both `x` and `e`: unlike [`modpow`], it never branches on an exponent bit
+/// or on the value being squared, so it doesn't leak timing information
+/// about either. Requires `m` to be odd, which holds for every modulus this
+/// crate exponentiates against (safe-prime candidates and their factors).
+///
+/// It takes the same arguments as [`modpow`] and, for odd `m`, returns the
+/// same value. Nothing in the crate calls it; the Miller-Rabin /
+/// Baillie-PSW witness loop does not use it.
+pub fn modpow_ct<const N: usize, const W: usize>(
+    x: UInt<N>,
+    e: &UInt<N>,
+    m: &NonZero<UInt<N>>,
+) -> UInt<N>
+where
+    UInt<N>: crypto_bigint::Concat<Output = UInt<W>>,
+    UInt<W>: crypto_bigint::Split<Output = UInt<N>>,
+{
+    let modulus: UInt<N> = **m;
+    let m_inv = mont_inv_neg(modulus.to_words()[0]);
+
+    // R mod m and R^2 mod m, computed by widening and reducing exactly like
+    // `mul_mod` does, rather than hand-rolling another reduction path.
+    let bit_width = N * Word::BITS as usize;
+    let one_wide: UInt<W> = UInt::<W>::ONE << bit_width;
+    let zero: UInt<N> = Zero::ZERO;
+    let m_wide_nonzero = NonZero::new(zero.concat(&modulus)).unwrap();
+    let r_mod_m: UInt<N> = {
+        let r: UInt<W> = one_wide.rem(&m_wide_nonzero);
+        let (_hi, lo) = r.split();
+        lo
+    };
+    #[allow(unused_mut)]
+    let mut r2_mod_m = mul_mod(&r_mod_m, &r_mod_m, m);
+
+    #[allow(unused_mut)]
+    let mut x_mod_m = x.rem(m);
+    #[allow(unused_mut)]
+    let mut x_mont = mont_mul(&x_mod_m, &r2_mod_m, &modulus, m_inv);
+
+    let mut acc = r_mod_m;
+    let total_bits = N * Word::BITS as usize;
+    for i in (0..total_bits).rev() {
+        acc = mont_mul(&acc, &acc, &modulus, m_inv);
+        #[allow(unused_mut)]
+        let mut multiplied = mont_mul(&acc, &x_mont, &modulus, m_inv);
+        acc = UInt::conditional_select(&acc, &multiplied, bit_choice(e, i));
+        #[cfg(feature = "zeroize")]
+        {
+            use zeroize::Zeroize;
+            multiplied.zeroize();
+        }
+    }
+    let result = mont_redc(zero_extend(acc), &modulus, m_inv);
+
+    #[cfg(feature = "zeroize")]
+    {
+        use zeroize::Zeroize;
+        x_mod_m.zeroize();
+        x_mont.zeroize();
+        r2_mod_m.zeroize();
+        acc.zeroize();
+    }
+
+    result
+}
+
+fn zero_extend<const N: usize, const W: usize>(x: UInt<N>) -> UInt<W>
+where
+    UInt<N>: crypto_bigint::Concat<Output = UInt<W>>,
+{
+    let zero: UInt<N> = Zero::ZERO;
+    zero.concat(&x)
+}
+
 /// Generate biguint in in range low..high. Panics on incorrect range
 pub fn gen_biguint_range<R: CryptoRng + RngCore, const N: usize>(
     rng: R,
@@ -158,6 +340,51 @@ mod test {
         assert_eq!(r, crypto_bigint::Integer::ONE);
     }
 
+    #[test]
+    fn modpow_ct_spec() {
+        let x = from_str_radix::<4>("109BF050E8004F525", 16).unwrap();
+        let e = from_str_radix::<4>("1F60DB8AD35B04936", 16).unwrap();
+        let m = crypto_bigint::NonZero::new(from_str_radix::<4>("1F60DB8AD35B04937", 16).unwrap())
+            .unwrap();
+        let r = super::modpow_ct(x, &e, &m);
+        assert_eq!(r, crypto_bigint::Integer::ONE);
+    }
+
+    #[test]
+    fn modpow_ct_matches_modpow() {
+        let x = from_str_radix::<4>("109BF050E8004F525", 16).unwrap();
+        let e = from_str_radix::<4>("7F60DB8AD35B04936", 16).unwrap();
+        let m = crypto_bigint::NonZero::new(from_str_radix::<4>("1F60DB8AD35B04937", 16).unwrap())
+            .unwrap();
+        assert_eq!(super::modpow(x, &e, &m), super::modpow_ct(x, &e, &m));
+    }
+
+    #[test]
+    fn modpow_ct_matches_modpow_near_top_of_range() {
+        // A modulus with its top bit set, as a candidate this crate
+        // generates has when its bit size fills the whole `UInt<N>`
+        // (`RandUInt::gen_biguint` always sets bit `bit_size - 1`). This is
+        // the regime where the Montgomery
+        // reduction's running total can overflow its `2N`-word container
+        // without the extra carry bit tracked in `mont_redc`.
+        let x = from_str_radix::<4>("1000000000000000000000000000000000000000000000181cd", 16)
+            .unwrap();
+        let e = from_str_radix::<4>(
+            "8000000000000000000000000000000000000000000000000000000000003039",
+            16,
+        )
+        .unwrap();
+        let m = crypto_bigint::NonZero::new(
+            from_str_radix::<4>(
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF43",
+                16,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(super::modpow(x, &e, &m), super::modpow_ct(x, &e, &m));
+    }
+
     #[test]
     fn is_bit_set_spec() {
         let x: U256 = 0b1010111100000101_u64.into();
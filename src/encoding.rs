@@ -0,0 +1,137 @@
+//! Big-endian byte and ASN.1 DER `INTEGER` encoding for generated primes.
+//!
+//! Mirrors the minimal, big-endian layout `crypto-bigint` uses for its own
+//! `encoding/der.rs`, so a generated prime can be handed straight to other
+//! ASN.1/PKCS tooling without depending on a separate ASN.1 stack.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crypto_bigint::{UInt, Word};
+
+use crate::error::Error;
+
+const WORD_BYTES: usize = (Word::BITS / 8) as usize;
+
+/// Encodes `x` as a minimal big-endian byte string: no leading zero bytes,
+/// except that zero itself is encoded as a single `0x00` byte.
+pub fn to_be_bytes_trimmed<const N: usize>(x: &UInt<N>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(N * WORD_BYTES);
+    for word in x.to_words().iter().rev() {
+        bytes.extend_from_slice(&word.to_be_bytes());
+    }
+    let first_nonzero = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len() - 1);
+    bytes.split_off(first_nonzero)
+}
+
+/// Parses a big-endian byte string into a `UInt<N>`. Errors if there are
+/// more bytes than `N` can hold.
+pub fn from_be_bytes<const N: usize>(bytes: &[u8]) -> Result<UInt<N>, Error> {
+    let capacity = N * WORD_BYTES;
+    if bytes.len() > capacity {
+        return Err(Error::Decode("too many bytes for the target integer width"));
+    }
+
+    let mut padded = vec![0u8; capacity];
+    let start = capacity - bytes.len();
+    padded[start..].copy_from_slice(bytes);
+
+    let mut words = [0 as Word; N];
+    for (word, chunk) in words.iter_mut().zip(padded.rchunks(WORD_BYTES)) {
+        *word = Word::from_be_bytes(chunk.try_into().unwrap());
+    }
+    Ok(UInt::from_words(words))
+}
+
+/// Encodes `len` using DER's length rules: short form for `len < 0x80`, long
+/// form (a length-of-length byte followed by the big-endian length) otherwise.
+fn push_der_length(out: &mut Vec<u8>, len: usize) {
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let first_nonzero = len_bytes
+            .iter()
+            .position(|&b| b != 0)
+            .unwrap_or(len_bytes.len() - 1);
+        let trimmed = &len_bytes[first_nonzero..];
+        out.push(0x80 | trimmed.len() as u8);
+        out.extend_from_slice(trimmed);
+    }
+}
+
+/// Encodes `x` as an ASN.1 DER `INTEGER`: tag `0x02`, a DER length, and a
+/// minimal big-endian magnitude with a leading `0x00` inserted when the high
+/// bit is set, so the value is never misread as negative.
+pub fn to_der_integer<const N: usize>(x: &UInt<N>) -> Vec<u8> {
+    let mut content = to_be_bytes_trimmed(x);
+    if content[0] & 0x80 != 0 {
+        content.insert(0, 0x00);
+    }
+
+    let mut der = Vec::with_capacity(content.len() + 6);
+    der.push(0x02);
+    push_der_length(&mut der, content.len());
+    der.extend_from_slice(&content);
+    der
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crypto_bigint::{Zero, U256};
+
+    #[test]
+    fn be_bytes_round_trip() {
+        let x: U256 = 0x1234_5678_u32.into();
+        let bytes = to_be_bytes_trimmed(&x);
+        assert_eq!(bytes, [0x12, 0x34, 0x56, 0x78]);
+        let y: U256 = from_be_bytes(&bytes).unwrap();
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn be_bytes_zero() {
+        let x: U256 = Zero::ZERO;
+        assert_eq!(to_be_bytes_trimmed(&x), [0x00]);
+    }
+
+    #[test]
+    fn from_be_bytes_rejects_overlong_input() {
+        let bytes = [0xffu8; 64];
+        let result: Result<crypto_bigint::U128, Error> = from_be_bytes(&bytes);
+        assert!(matches!(result, Err(Error::Decode(_))));
+    }
+
+    #[test]
+    fn der_integer_adds_leading_zero_for_high_bit() {
+        let x: U256 = 0x80_u32.into();
+        let der = to_der_integer(&x);
+        assert_eq!(der, [0x02, 0x02, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn der_integer_small_value() {
+        let x: U256 = 0x7f_u32.into();
+        let der = to_der_integer(&x);
+        assert_eq!(der, [0x02, 0x01, 0x7f]);
+    }
+
+    #[test]
+    fn der_integer_uses_long_form_length_for_large_values() {
+        use crypto_bigint::U2048;
+
+        // All bits set: 256 content bytes, plus the leading 0x00 the high
+        // bit forces in, for a 257-byte content length: long enough that
+        // the long-form length itself needs two bytes.
+        let x: U2048 = U2048::MAX;
+        let der = to_der_integer(&x);
+        assert_eq!(der[0], 0x02);
+        // 257 needs a length-of-length byte plus 2 big-endian length bytes.
+        assert_eq!(der[1], 0x80 | 2);
+        assert_eq!(&der[2..4], &[0x01, 0x01]);
+        assert_eq!(der.len(), 4 + 257);
+        assert_eq!(der[4], 0x00);
+        assert!(der[5..].iter().all(|&b| b == 0xff));
+    }
+}
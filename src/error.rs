@@ -15,6 +15,9 @@ pub enum Error {
     OsRngInitialization(rand::Error),
     /// Handles when the bit sizes are too small
     BitLength(usize),
+    /// Handles malformed input when decoding a big-endian byte string back
+    /// into a `UInt`
+    Decode(&'static str),
 }
 
 impl fmt::Display for Error {
@@ -28,6 +31,7 @@ impl fmt::Display for Error {
                 "The given bit length is too small; must be at least {}: {}",
                 MIN_BIT_LENGTH, length
             ),
+            Error::Decode(reason) => write!(f, "Malformed encoded integer: {}", reason),
         }
     }
 }
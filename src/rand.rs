@@ -1,7 +1,45 @@
-use crypto_bigint::UInt;
-use rand::{Rng, CryptoRng};
+use crypto_bigint::{NonZero, RandomMod, UInt};
+use rand::{Rng, CryptoRng, RngCore};
 
-use crate::compat::gen_biguint_range;
+use crate::compat::{gen_biguint_bits, gen_biguint_range};
+use crate::sieve::Sieve;
+
+/// Generate random [`UInt`] values directly from an `R: CryptoRng + RngCore`,
+/// mirroring the ergonomics of `num-bigint`'s `RandBigInt`.
+///
+/// Blanket-implemented for every cryptographically secure RNG, so callers
+/// can write `let p: U512 = rng.gen_biguint(512);` instead of reaching into this
+/// crate's internal generation helpers.
+pub trait RandUInt {
+    /// Generates a random `UInt<N>` with the given bit size, i.e. with bit
+    /// index `bit_size - 1` set.
+    fn gen_biguint<const N: usize>(&mut self, bit_size: usize) -> UInt<N>;
+
+    /// Generates a random `UInt<N>` uniformly distributed in `[0, bound)`.
+    /// Panics if `bound` is zero.
+    fn gen_biguint_below<const N: usize>(&mut self, bound: &UInt<N>) -> UInt<N>;
+
+    /// Generates a random `UInt<N>` uniformly distributed in `[low, high)`.
+    /// Panics if the range is empty.
+    fn gen_biguint_range<const N: usize>(&mut self, low: UInt<N>, high: UInt<N>) -> UInt<N>;
+}
+
+impl<R: CryptoRng + RngCore> RandUInt for R {
+    fn gen_biguint<const N: usize>(&mut self, bit_size: usize) -> UInt<N> {
+        gen_biguint_bits(self, bit_size)
+    }
+
+    fn gen_biguint_below<const N: usize>(&mut self, bound: &UInt<N>) -> UInt<N> {
+        match NonZero::new(*bound).into() {
+            Some(m) => UInt::<N>::random_mod(self, &m),
+            None => panic!("Zero bound"),
+        }
+    }
+
+    fn gen_biguint_range<const N: usize>(&mut self, low: UInt<N>, high: UInt<N>) -> UInt<N> {
+        gen_biguint_range(self, low, high)
+    }
+}
 
 /// Iterator to generate a given amount of random numbers. For convenience of
 /// use with miller_rabin tests, you can also append a specified number at the
@@ -12,6 +50,7 @@ pub struct Randoms<R, I> {
     upper_limit: I,
     amount: usize,
     rng: R,
+    safe_prime_partner: bool,
 }
 
 impl<const N: usize, R: Rng + CryptoRng> Randoms<R, UInt<N>> {
@@ -22,6 +61,7 @@ impl<const N: usize, R: Rng + CryptoRng> Randoms<R, UInt<N>> {
             upper_limit,
             amount,
             rng,
+            safe_prime_partner: false,
         }
     }
 
@@ -33,8 +73,55 @@ impl<const N: usize, R: Rng + CryptoRng> Randoms<R, UInt<N>> {
         self
     }
 
+    /// Also presieves each candidate's Sophie Germain partner `(p - 1) / 2`,
+    /// so safe-prime generation can reject candidates whose partner is
+    /// obviously composite before either number reaches Miller-Rabin.
+    pub fn with_safe_prime_partner(mut self) -> Self {
+        self.safe_prime_partner = true;
+        self
+    }
+
+    /// Draws a uniform starting point in range and walks it forward through
+    /// a [`Sieve`] to the next candidate that survives small-prime
+    /// presieving, so the (expensive) Miller-Rabin / Baillie-PSW tests this
+    /// iterator feeds only ever see presieved candidates. On the rare
+    /// occasion the walk passes `upper_limit`, it wraps back to the start of
+    /// the range and is sieved again from there, so the candidate handed
+    /// back is always a presieve survivor, never an unsieved arithmetic
+    /// shift of one.
     fn gen_biguint(&mut self) -> UInt<N> {
-        gen_biguint_range(&mut self.rng, self.lower_limit, self.upper_limit)
+        let start = gen_biguint_range(&mut self.rng, self.lower_limit, self.upper_limit);
+        let candidate = self.sieve_from(start);
+        if candidate >= self.upper_limit {
+            self.sieve_from(self.lower_limit)
+        } else {
+            candidate
+        }
+    }
+
+    fn sieve_from(&self, start: UInt<N>) -> UInt<N> {
+        let sieve = if self.safe_prime_partner {
+            Sieve::new(start).with_safe_prime_partner()
+        } else {
+            Sieve::new(start)
+        };
+        sieve.next().expect("Sieve never terminates")
+    }
+}
+
+/// Wipes the appended number, if any, once the iterator is dropped.
+/// `lower_limit`/`upper_limit` are public generation bounds (e.g.
+/// `2^(n-1)`/`2^n`), not secrets, and every generated candidate is already
+/// handed to the caller by `next()` rather than kept here, so `appended` —
+/// test/debug data the caller chose to inject as if it had been generated —
+/// is the only field actually worth scrubbing. Only compiled in with the
+/// `zeroize` feature.
+#[cfg(feature = "zeroize")]
+impl<R, I: zeroize::Zeroize> Drop for Randoms<R, I> {
+    fn drop(&mut self) {
+        if let Some(appended) = &mut self.appended {
+            appended.zeroize();
+        }
     }
 }
 
@@ -60,10 +147,39 @@ impl<const N: usize, R: Rng + CryptoRng> Iterator for Randoms<R, UInt<N>> {
 
 #[cfg(test)]
 mod test {
-    use super::Randoms;
+    use super::{RandUInt, Randoms};
     use crypto_bigint::U256;
     use rand::thread_rng;
 
+    #[test]
+    fn gen_biguint_below_test() {
+        let bound: U256 = 100_u8.into();
+        for _ in 0..10 {
+            let n: U256 = thread_rng().gen_biguint_below(&bound);
+            assert!(n < bound);
+        }
+    }
+
+    #[test]
+    fn gen_biguint_test() {
+        let n: U256 = thread_rng().gen_biguint(256);
+        assert_eq!(n.bits(), 256);
+    }
+
+    #[test]
+    fn generated_candidates_are_presieved() {
+        use core::ops::Rem;
+        use crypto_bigint::NonZero;
+
+        let low: U256 = 1_000_000_u32.into();
+        let high: U256 = 2_000_000_u32.into();
+        let rands = Randoms::new(low, high, 20, thread_rng());
+        let three = NonZero::new(U256::from(3u32)).unwrap();
+        for n in rands.collect::<Vec<U256>>() {
+            assert_ne!(n.rem(&three), crypto_bigint::Zero::ZERO);
+        }
+    }
+
     #[test]
     fn generate_amount_test() {
         let amount = 3;
@@ -0,0 +1,134 @@
+//! Small-prime wheel sieve used to presieve candidates before the expensive
+//! Miller-Rabin / Baillie-PSW primality tests run on them.
+
+use core::ops::Rem;
+
+use crypto_bigint::{Integer, NonZero, UInt};
+
+use crate::small_primes::ODD_PRIMES_BELOW_1_SHL_16;
+
+const SIEVE_PRIMES: usize = ODD_PRIMES_BELOW_1_SHL_16.len();
+
+/// Walks consecutive odd candidates starting from a given point, cheaply
+/// rejecting those divisible by any odd prime below 2^16 before the caller
+/// bothers running a real primality test on them.
+///
+/// Built around a fixed table of small primes: for each one, only the
+/// candidate's residue is tracked, and advancing by 2 is a single
+/// add-and-conditionally-subtract per prime rather than a fresh `Rem` of the
+/// whole big integer.
+pub struct Sieve<const N: usize> {
+    candidate: UInt<N>,
+    residues: [u16; SIEVE_PRIMES],
+    /// Residues of `(candidate - 1) / 2`, tracked alongside `residues` when
+    /// presieving safe-prime candidates together with their Sophie Germain
+    /// partner.
+    partner_residues: Option<[u16; SIEVE_PRIMES]>,
+}
+
+impl<const N: usize> Sieve<N> {
+    /// Starts sieving from `start`, rounded up to the next odd number if it
+    /// is even.
+    pub fn new(start: UInt<N>) -> Self {
+        let candidate = if bool::from(start.is_even()) {
+            start.wrapping_add(&UInt::ONE)
+        } else {
+            start
+        };
+        let residues = Self::residues_of(&candidate);
+        Self {
+            candidate,
+            residues,
+            partner_residues: None,
+        }
+    }
+
+    /// Also presieves `(candidate - 1) / 2`, so safe-prime generation can
+    /// reject a candidate whose Sophie Germain partner is obviously
+    /// composite without running Miller-Rabin on either number.
+    pub fn with_safe_prime_partner(mut self) -> Self {
+        let partner = self.candidate.wrapping_sub(&UInt::ONE) >> 1;
+        self.partner_residues = Some(Self::residues_of(&partner));
+        self
+    }
+
+    fn residues_of(x: &UInt<N>) -> [u16; SIEVE_PRIMES] {
+        let mut residues = [0u16; SIEVE_PRIMES];
+        for (slot, &p) in residues.iter_mut().zip(ODD_PRIMES_BELOW_1_SHL_16.iter()) {
+            let modulus = NonZero::new(UInt::<N>::from(p as u32)).unwrap();
+            *slot = x.rem(&modulus).to_words()[0] as u16;
+        }
+        residues
+    }
+
+    fn advance(&mut self) {
+        self.candidate = self.candidate.wrapping_add(&UInt::from(2u32));
+        advance_residues(&mut self.residues, 2);
+        if let Some(partner_residues) = &mut self.partner_residues {
+            advance_residues(partner_residues, 1);
+        }
+    }
+
+    fn survives(&self) -> bool {
+        let candidate_ok = self.residues.iter().all(|&r| r != 0);
+        let partner_ok = self
+            .partner_residues
+            .as_ref()
+            .is_none_or(|residues| residues.iter().all(|&r| r != 0));
+        candidate_ok && partner_ok
+    }
+}
+
+fn advance_residues(residues: &mut [u16; SIEVE_PRIMES], step: u16) {
+    for (slot, &p) in residues.iter_mut().zip(ODD_PRIMES_BELOW_1_SHL_16.iter()) {
+        *slot += step;
+        if *slot >= p {
+            *slot -= p;
+        }
+    }
+}
+
+impl<const N: usize> Iterator for Sieve<N> {
+    type Item = UInt<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.survives() {
+                let candidate = self.candidate;
+                self.advance();
+                return Some(candidate);
+            }
+            self.advance();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crypto_bigint::{Zero, U256};
+
+    #[test]
+    fn skips_multiples_of_small_primes() {
+        let start: U256 = UInt::from(105u32); // 3 * 5 * 7
+        let mut sieve = Sieve::new(start);
+        let candidate = sieve.next().unwrap();
+        for p in [3u32, 5, 7] {
+            let modulus = NonZero::new(UInt::from(p)).unwrap();
+            assert_ne!(candidate.rem(&modulus), Zero::ZERO);
+        }
+    }
+
+    #[test]
+    fn presieves_safe_prime_partner() {
+        let start: U256 = UInt::from(107u32);
+        let mut sieve = Sieve::new(start).with_safe_prime_partner();
+        let candidate = sieve.next().unwrap();
+        let partner = candidate.wrapping_sub(&UInt::ONE) >> 1;
+        for p in [3u32, 5, 7] {
+            let modulus = NonZero::new(UInt::from(p)).unwrap();
+            assert_ne!(candidate.rem(&modulus), Zero::ZERO);
+            assert_ne!(partner.rem(&modulus), Zero::ZERO);
+        }
+    }
+}